@@ -0,0 +1,56 @@
+use crate::{PitchClass, PitchClassSet};
+
+pub fn from(set: &PitchClassSet) -> PitchClassSet {
+    within(set, &aggregate())
+}
+
+pub fn within(set: &PitchClassSet, universe: &PitchClassSet) -> PitchClassSet {
+    let present: Vec<u32> = distinct_values(set);
+
+    let complement = distinct_values(universe)
+        .into_iter()
+        .filter(|pc| !present.contains(pc))
+        .map(PitchClass::from)
+        .collect();
+
+    PitchClassSet::new(complement)
+}
+
+fn distinct_values(set: &PitchClassSet) -> Vec<u32> {
+    let mut values: Vec<u32> = set.set().iter().map(|pc| pc.to_u32()).collect();
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+fn aggregate() -> PitchClassSet {
+    PitchClassSet::new((0..12).map(PitchClass::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from, within, PitchClassSet};
+    use crate::{set, PitchClass::*};
+
+    #[test]
+    fn complement_of_a_triad() {
+        let set: PitchClassSet = set![C, E, G];
+        let expected: PitchClassSet = set![Cs, D, Ef, F, Fs, Af, A, Bf, B];
+        assert_eq!(from(&set), expected);
+    }
+
+    #[test]
+    fn complement_deduplicates_enharmonic_spellings() {
+        let set: PitchClassSet = set![C, Cs, Df];
+        let expected: PitchClassSet = set![D, Ef, E, F, Fs, G, Af, A, Bf, B];
+        assert_eq!(from(&set), expected);
+    }
+
+    #[test]
+    fn complement_within_a_diatonic_universe() {
+        let set: PitchClassSet = set![C, E, G];
+        let universe: PitchClassSet = set![C, D, E, F, G, A, B];
+        let expected: PitchClassSet = set![D, F, A, B];
+        assert_eq!(within(&set, &universe), expected);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::forte_data::forte_catalog_data;
+use phf::phf_map;
+
+/// A single entry in the [CATALOG]: a set class's Forte number and
+/// precomputed interval-class vector.
+pub struct ForteEntry {
+    pub forte_number: &'static str,
+    pub interval_vector: [u32; 6],
+}
+
+/// Builds [CATALOG] from the shared [forte_catalog_data] literal list,
+/// the same data consumed by [crate::set_class], so the two lookups can
+/// never drift out of sync with each other.
+macro_rules! build_phf_catalog {
+    ($(($mask:expr, $forte:expr, $prime:expr, $iv:expr)),* $(,)?) => {
+        phf_map! {
+            $($mask => ForteEntry { forte_number: $forte, interval_vector: $iv }),*
+        }
+    };
+}
+
+/// A compile-time perfect-hash lookup from the packed 12-bit bitmask of a
+/// prime form (bit `i` set iff pitch class `i` is present) to its
+/// [ForteEntry].
+///
+/// Currently covers the monad, dyads, trichords, tetrachords, octachords,
+/// nonachords, decachords, and the hendecachord (96 of the 220 Forte set
+/// classes). Pentachords, hexachords, and septachords are not yet
+/// included.
+pub static CATALOG: phf::Map<u16, ForteEntry> = forte_catalog_data!(build_phf_catalog);
+
+/// Packs a prime form into the 12-bit bitmask used as a [CATALOG] key: bit
+/// `i` is set iff pitch class `i` is present.
+pub fn pack(prime_form: &[u32]) -> u16 {
+    prime_form.iter().fold(0u16, |mask, &pc| mask | (1 << pc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pack, CATALOG};
+
+    #[test]
+    fn packs_a_prime_form_into_a_bitmask() {
+        assert_eq!(pack(&[0, 3, 7]), 0b0000_1000_1001);
+    }
+
+    #[test]
+    fn looks_up_a_trichord_by_bitmask() {
+        let entry = CATALOG.get(&pack(&[0, 3, 7])).unwrap();
+        assert_eq!(entry.forte_number, "3-11");
+    }
+
+    #[test]
+    fn looks_up_a_nonachord_by_bitmask() {
+        let entry = CATALOG
+            .get(&pack(&[0, 1, 2, 3, 4, 5, 6, 7, 8]))
+            .unwrap();
+        assert_eq!(entry.forte_number, "9-1");
+    }
+}
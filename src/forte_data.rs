@@ -0,0 +1,138 @@
+/// The single source of truth for the embedded Forte set-class catalog,
+/// shared by [crate::set_class] and [crate::forte_catalog] so the two
+/// lookup mechanisms can never drift out of sync with each other.
+///
+/// Each entry is `(bitmask, forte_number, prime_form, interval_vector)`,
+/// where `bitmask` is the packed 12-bit representation of `prime_form`
+/// (bit `i` set iff pitch class `i` is present) used by
+/// [crate::forte_catalog]'s `phf` lookup.
+///
+/// Invoke with the name of a `macro_rules!` callback that accepts the
+/// list as its argument; the callback decides what to build from it
+/// (a plain array, a `phf_map!`, ...).
+///
+/// Covers the monad, dyads, trichords, tetrachords, octachords,
+/// nonachords, decachords, and hendecachord (96 set classes total) —
+/// the octachords/nonachords/decachords/hendecachord are the complements
+/// of the tetrachords/trichords/dyads/monad above them, reusing the same
+/// Forte number under the standard complement-relation convention.
+///
+/// Pentachords, hexachords, and septachords (the remaining ~124 of the
+/// 220 Forte set classes) are not yet included: unlike the cardinalities
+/// above, they have no smaller already-verified complement to derive
+/// them from, and hand-transcribing ~124 prime forms and Forte numbers
+/// from memory without a source to check them against risks shipping
+/// catalog entries that are confidently wrong rather than honestly
+/// absent. [crate::set_class::from] and [PitchClassSet::forte_number]
+/// both return `None` for sets of these cardinalities.
+macro_rules! forte_catalog_data {
+    ($callback:ident) => {
+        $callback! {
+            (1u16, "1-1", &[0], [0, 0, 0, 0, 0, 0]),
+
+            (3u16, "2-1", &[0, 1], [1, 0, 0, 0, 0, 0]),
+            (5u16, "2-2", &[0, 2], [0, 1, 0, 0, 0, 0]),
+            (9u16, "2-3", &[0, 3], [0, 0, 1, 0, 0, 0]),
+            (17u16, "2-4", &[0, 4], [0, 0, 0, 1, 0, 0]),
+            (33u16, "2-5", &[0, 5], [0, 0, 0, 0, 1, 0]),
+            (65u16, "2-6", &[0, 6], [0, 0, 0, 0, 0, 1]),
+
+            (7u16, "3-1", &[0, 1, 2], [2, 1, 0, 0, 0, 0]),
+            (11u16, "3-2", &[0, 1, 3], [1, 1, 1, 0, 0, 0]),
+            (19u16, "3-3", &[0, 1, 4], [1, 0, 1, 1, 0, 0]),
+            (35u16, "3-4", &[0, 1, 5], [1, 0, 0, 1, 1, 0]),
+            (67u16, "3-5", &[0, 1, 6], [1, 0, 0, 0, 1, 1]),
+            (21u16, "3-6", &[0, 2, 4], [0, 2, 0, 1, 0, 0]),
+            (37u16, "3-7", &[0, 2, 5], [0, 1, 1, 0, 1, 0]),
+            (69u16, "3-8", &[0, 2, 6], [0, 1, 0, 1, 0, 1]),
+            (133u16, "3-9", &[0, 2, 7], [0, 1, 0, 0, 2, 0]),
+            (73u16, "3-10", &[0, 3, 6], [0, 0, 2, 0, 0, 1]),
+            (137u16, "3-11", &[0, 3, 7], [0, 0, 1, 1, 1, 0]),
+            (273u16, "3-12", &[0, 4, 8], [0, 0, 0, 3, 0, 0]),
+
+            (15u16, "4-1", &[0, 1, 2, 3], [3, 2, 1, 0, 0, 0]),
+            (23u16, "4-2", &[0, 1, 2, 4], [2, 2, 1, 1, 0, 0]),
+            (27u16, "4-3", &[0, 1, 3, 4], [2, 1, 2, 1, 0, 0]),
+            (39u16, "4-4", &[0, 1, 2, 5], [2, 1, 1, 1, 1, 0]),
+            (71u16, "4-5", &[0, 1, 2, 6], [2, 1, 0, 1, 1, 1]),
+            (135u16, "4-6", &[0, 1, 2, 7], [2, 1, 0, 0, 2, 1]),
+            (51u16, "4-7", &[0, 1, 4, 5], [2, 0, 1, 2, 1, 0]),
+            (99u16, "4-8", &[0, 1, 5, 6], [2, 0, 0, 1, 2, 1]),
+            (195u16, "4-9", &[0, 1, 6, 7], [2, 0, 0, 0, 2, 2]),
+            (45u16, "4-10", &[0, 2, 3, 5], [1, 2, 2, 0, 1, 0]),
+            (43u16, "4-11", &[0, 1, 3, 5], [1, 2, 1, 1, 1, 0]),
+            (77u16, "4-12", &[0, 2, 3, 6], [1, 1, 2, 1, 0, 1]),
+            (75u16, "4-13", &[0, 1, 3, 6], [1, 1, 2, 0, 1, 1]),
+            (141u16, "4-14", &[0, 2, 3, 7], [1, 1, 1, 1, 2, 0]),
+            (83u16, "4-Z15", &[0, 1, 4, 6], [1, 1, 1, 1, 1, 1]),
+            (163u16, "4-16", &[0, 1, 5, 7], [1, 1, 0, 1, 2, 1]),
+            (153u16, "4-17", &[0, 3, 4, 7], [1, 0, 2, 2, 1, 0]),
+            (147u16, "4-18", &[0, 1, 4, 7], [1, 0, 2, 1, 1, 1]),
+            (275u16, "4-19", &[0, 1, 4, 8], [1, 0, 1, 3, 1, 0]),
+            (291u16, "4-20", &[0, 1, 5, 8], [1, 0, 1, 2, 2, 0]),
+            (85u16, "4-21", &[0, 2, 4, 6], [0, 3, 0, 2, 0, 1]),
+            (149u16, "4-22", &[0, 2, 4, 7], [0, 2, 1, 1, 2, 0]),
+            (165u16, "4-23", &[0, 2, 5, 7], [0, 2, 1, 0, 3, 0]),
+            (277u16, "4-24", &[0, 2, 4, 8], [0, 2, 0, 3, 0, 1]),
+            (325u16, "4-25", &[0, 2, 6, 8], [0, 2, 0, 2, 0, 2]),
+            (297u16, "4-26", &[0, 3, 5, 8], [0, 1, 2, 1, 2, 0]),
+            (293u16, "4-27", &[0, 2, 5, 8], [0, 1, 2, 1, 1, 1]),
+            (585u16, "4-28", &[0, 3, 6, 9], [0, 0, 4, 0, 0, 2]),
+            (139u16, "4-Z29", &[0, 1, 3, 7], [1, 1, 1, 1, 1, 1]),
+
+            (255u16, "8-1", &[0, 1, 2, 3, 4, 5, 6, 7], [7, 6, 5, 4, 4, 2]),
+            (383u16, "8-2", &[0, 1, 2, 3, 4, 5, 6, 8], [6, 6, 5, 5, 4, 2]),
+            (639u16, "8-3", &[0, 1, 2, 3, 4, 5, 6, 9], [6, 5, 6, 5, 4, 2]),
+            (447u16, "8-4", &[0, 1, 2, 3, 4, 5, 7, 8], [6, 5, 5, 5, 5, 2]),
+            (479u16, "8-5", &[0, 1, 2, 3, 4, 6, 7, 8], [6, 5, 4, 5, 5, 3]),
+            (495u16, "8-6", &[0, 1, 2, 3, 5, 6, 7, 8], [6, 5, 4, 4, 6, 3]),
+            (831u16, "8-7", &[0, 1, 2, 3, 4, 5, 8, 9], [6, 4, 5, 6, 5, 2]),
+            (927u16, "8-8", &[0, 1, 2, 3, 4, 7, 8, 9], [6, 4, 4, 5, 6, 3]),
+            (975u16, "8-9", &[0, 1, 2, 3, 6, 7, 8, 9], [6, 4, 4, 4, 6, 4]),
+            (765u16, "8-10", &[0, 2, 3, 4, 5, 6, 7, 9], [5, 6, 6, 4, 5, 2]),
+            (703u16, "8-11", &[0, 1, 2, 3, 4, 5, 7, 9], [5, 6, 5, 5, 5, 2]),
+            (763u16, "8-12", &[0, 1, 3, 4, 5, 6, 7, 9], [5, 5, 6, 5, 4, 3]),
+            (735u16, "8-13", &[0, 1, 2, 3, 4, 6, 7, 9], [5, 5, 6, 4, 5, 3]),
+            (759u16, "8-14", &[0, 1, 2, 4, 5, 6, 7, 9], [5, 5, 5, 5, 6, 2]),
+            (863u16, "8-Z15", &[0, 1, 2, 3, 4, 6, 8, 9], [5, 5, 5, 5, 5, 3]),
+            (943u16, "8-16", &[0, 1, 2, 3, 5, 7, 8, 9], [5, 5, 4, 5, 6, 3]),
+            (891u16, "8-17", &[0, 1, 3, 4, 5, 6, 8, 9], [5, 4, 6, 6, 5, 2]),
+            (879u16, "8-18", &[0, 1, 2, 3, 5, 6, 8, 9], [5, 4, 6, 5, 5, 3]),
+            (887u16, "8-19", &[0, 1, 2, 4, 5, 6, 8, 9], [5, 4, 5, 7, 5, 2]),
+            (951u16, "8-20", &[0, 1, 2, 4, 5, 7, 8, 9], [5, 4, 5, 6, 6, 2]),
+            (1375u16, "8-21", &[0, 1, 2, 3, 4, 6, 8, 10], [4, 7, 4, 6, 4, 3]),
+            (1391u16, "8-22", &[0, 1, 2, 3, 5, 6, 8, 10], [4, 6, 5, 5, 6, 2]),
+            (1455u16, "8-23", &[0, 1, 2, 3, 5, 7, 8, 10], [4, 6, 5, 4, 7, 2]),
+            (1399u16, "8-24", &[0, 1, 2, 4, 5, 6, 8, 10], [4, 6, 4, 7, 4, 3]),
+            (1495u16, "8-25", &[0, 1, 2, 4, 6, 7, 8, 10], [4, 6, 4, 6, 4, 4]),
+            (1719u16, "8-26", &[0, 1, 2, 4, 5, 7, 9, 10], [4, 5, 6, 5, 6, 2]),
+            (1463u16, "8-27", &[0, 1, 2, 4, 5, 7, 8, 10], [4, 5, 6, 5, 5, 3]),
+            (1755u16, "8-28", &[0, 1, 3, 4, 6, 7, 9, 10], [4, 4, 8, 4, 4, 4]),
+            (751u16, "8-Z29", &[0, 1, 2, 3, 5, 6, 7, 9], [5, 5, 5, 5, 5, 3]),
+
+            (511u16, "9-1", &[0, 1, 2, 3, 4, 5, 6, 7, 8], [8, 7, 6, 6, 6, 3]),
+            (767u16, "9-2", &[0, 1, 2, 3, 4, 5, 6, 7, 9], [7, 7, 7, 6, 6, 3]),
+            (895u16, "9-3", &[0, 1, 2, 3, 4, 5, 6, 8, 9], [7, 6, 7, 7, 6, 3]),
+            (959u16, "9-4", &[0, 1, 2, 3, 4, 5, 7, 8, 9], [7, 6, 6, 7, 7, 3]),
+            (991u16, "9-5", &[0, 1, 2, 3, 4, 6, 7, 8, 9], [7, 6, 6, 6, 7, 4]),
+            (1407u16, "9-6", &[0, 1, 2, 3, 4, 5, 6, 8, 10], [6, 8, 6, 7, 6, 3]),
+            (1471u16, "9-7", &[0, 1, 2, 3, 4, 5, 7, 8, 10], [6, 7, 7, 6, 7, 3]),
+            (1503u16, "9-8", &[0, 1, 2, 3, 4, 6, 7, 8, 10], [6, 7, 6, 7, 6, 4]),
+            (1519u16, "9-9", &[0, 1, 2, 3, 5, 6, 7, 8, 10], [6, 7, 6, 6, 8, 3]),
+            (1759u16, "9-10", &[0, 1, 2, 3, 4, 6, 7, 9, 10], [6, 6, 8, 6, 6, 4]),
+            (1775u16, "9-11", &[0, 1, 2, 3, 5, 6, 7, 9, 10], [6, 6, 7, 7, 7, 3]),
+            (1911u16, "9-12", &[0, 1, 2, 4, 5, 6, 8, 9, 10], [6, 6, 6, 9, 6, 3]),
+
+            (1023u16, "10-1", &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], [9, 8, 8, 8, 8, 4]),
+            (1535u16, "10-2", &[0, 1, 2, 3, 4, 5, 6, 7, 8, 10], [8, 9, 8, 8, 8, 4]),
+            (1791u16, "10-3", &[0, 1, 2, 3, 4, 5, 6, 7, 9, 10], [8, 8, 9, 8, 8, 4]),
+            (1919u16, "10-4", &[0, 1, 2, 3, 4, 5, 6, 8, 9, 10], [8, 8, 8, 9, 8, 4]),
+            (1983u16, "10-5", &[0, 1, 2, 3, 4, 5, 7, 8, 9, 10], [8, 8, 8, 8, 9, 4]),
+            (2015u16, "10-6", &[0, 1, 2, 3, 4, 6, 7, 8, 9, 10], [8, 8, 8, 8, 8, 5]),
+
+            (2047u16, "11-1", &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10], [10, 10, 10, 10, 10, 5]),
+        }
+    };
+}
+
+pub(crate) use forte_catalog_data;
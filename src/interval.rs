@@ -0,0 +1,195 @@
+use crate::{utils, PitchClass};
+
+/// The diatonic type of a [NamedInterval], derived from the letter distance
+/// between two pitch classes (ignoring accidentals).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalType {
+    Unison,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+    Sixth,
+    Seventh,
+}
+
+/// The quality of a [NamedInterval], derived from comparing its actual
+/// semitone distance against the perfect/major reference size for its
+/// [IntervalType].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalQuality {
+    Diminished,
+    Minor,
+    Perfect,
+    Major,
+    Augmented,
+}
+
+/// The direction in which a [NamedInterval] is spelled.
+///
+/// Since a bare [PitchClass] carries no octave, `named` always measures the
+/// interval ascending from its first argument to its second; `Descending`
+/// is reserved for octave-aware callers built on top of this type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+/// A properly spelled common-practice interval between two pitch classes,
+/// carrying its diatonic type, quality, and direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamedInterval {
+    pub interval_type: IntervalType,
+    pub quality: IntervalQuality,
+    pub direction: Direction,
+}
+
+fn letter_index(pc: PitchClass) -> u32 {
+    use PitchClass::*;
+    match pc {
+        Cf | C | Cs | Css => 0,
+        Dff | Df | D | Ds | Dss => 1,
+        Eff | Ef | E | Es => 2,
+        Ff | F | Fs | Fss => 3,
+        Gff | Gf | G | Gs | Gss => 4,
+        Aff | Af | A | As | Ass => 5,
+        Bff | Bf | B | Bs => 6,
+    }
+}
+
+const REFERENCE_SIZES: [u32; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+fn interval_type(letter_distance: u32) -> IntervalType {
+    use IntervalType::*;
+    match letter_distance {
+        0 => Unison,
+        1 => Second,
+        2 => Third,
+        3 => Fourth,
+        4 => Fifth,
+        5 => Sixth,
+        6 => Seventh,
+        n => panic!("letter distance should never be {n}"),
+    }
+}
+
+fn is_perfect_kind(interval_type: IntervalType) -> bool {
+    matches!(
+        interval_type,
+        IntervalType::Unison | IntervalType::Fourth | IntervalType::Fifth
+    )
+}
+
+fn interval_quality(interval_type: IntervalType, diff: i32) -> IntervalQuality {
+    use IntervalQuality::*;
+    match diff {
+        0 if is_perfect_kind(interval_type) => Perfect,
+        0 => Major,
+        -1 if is_perfect_kind(interval_type) => Diminished,
+        -1 => Minor,
+        d if d < -1 => Diminished,
+        _ => Augmented,
+    }
+}
+
+/// Computes the properly spelled common-practice interval from `a` up to
+/// `b`, taking into account the enharmonic spelling of both pitch classes.
+///
+/// ```
+/// # use forte::{interval, PitchClass::*};
+/// # use forte::interval::{IntervalQuality, IntervalType};
+/// let named = interval::named(Cs, F);
+/// assert_eq!(named.interval_type, IntervalType::Fourth);
+/// assert_eq!(named.quality, IntervalQuality::Diminished);
+/// ```
+///
+/// This correctly distinguishes `Cs -> F` (a diminished fourth) from
+/// `Cs -> Es` (a major third), which a pure mod-12 semitone count cannot.
+/// ```
+/// # use forte::{interval, PitchClass::*};
+/// # use forte::interval::{IntervalQuality, IntervalType};
+/// let named = interval::named(Cs, Es);
+/// assert_eq!(named.interval_type, IntervalType::Third);
+/// assert_eq!(named.quality, IntervalQuality::Major);
+/// ```
+pub fn named(a: PitchClass, b: PitchClass) -> NamedInterval {
+    let letter_distance = (letter_index(b) as i32 - letter_index(a) as i32).rem_euclid(7) as u32;
+    let interval_type = interval_type(letter_distance);
+
+    let actual = utils::get_interval_between(a.to_u32(), b.to_u32()) as i32;
+    let reference = REFERENCE_SIZES[letter_distance as usize] as i32;
+    let quality = interval_quality(interval_type, actual - reference);
+
+    NamedInterval {
+        interval_type,
+        quality,
+        direction: Direction::Ascending,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{named, Direction, IntervalQuality::*, IntervalType::*, NamedInterval};
+    use crate::PitchClass::*;
+
+    #[test]
+    fn diminished_fourth() {
+        assert_eq!(
+            named(Cs, F),
+            NamedInterval {
+                interval_type: Fourth,
+                quality: Diminished,
+                direction: Direction::Ascending,
+            }
+        );
+    }
+
+    #[test]
+    fn major_third() {
+        assert_eq!(
+            named(Cs, Es),
+            NamedInterval {
+                interval_type: Third,
+                quality: Major,
+                direction: Direction::Ascending,
+            }
+        );
+    }
+
+    #[test]
+    fn perfect_fifth() {
+        assert_eq!(
+            named(C, G),
+            NamedInterval {
+                interval_type: Fifth,
+                quality: Perfect,
+                direction: Direction::Ascending,
+            }
+        );
+    }
+
+    #[test]
+    fn minor_seventh() {
+        assert_eq!(
+            named(D, C),
+            NamedInterval {
+                interval_type: Seventh,
+                quality: Minor,
+                direction: Direction::Ascending,
+            }
+        );
+    }
+
+    #[test]
+    fn augmented_second() {
+        assert_eq!(
+            named(C, Ds),
+            NamedInterval {
+                interval_type: Second,
+                quality: Augmented,
+                direction: Direction::Ascending,
+            }
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{utils, PitchClassSet};
+
+pub fn from(set: &PitchClassSet) -> [u32; 6] {
+    let mut pcs: Vec<u32> = set.set().iter().map(|pc| pc.to_u32()).collect();
+    pcs.sort_unstable();
+    pcs.dedup();
+
+    let mut vector = [0u32; 6];
+    for (i, a) in pcs.iter().enumerate() {
+        for b in &pcs[i + 1..] {
+            let ic = interval_class(*a, *b);
+            vector[ic as usize - 1] += 1;
+        }
+    }
+    vector
+}
+
+fn interval_class(a: u32, b: u32) -> u32 {
+    let d = utils::get_interval_between(a, b);
+    d.min(12 - d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from;
+    use crate::{set, PitchClass::*, PitchClassSet};
+
+    #[test]
+    fn interval_vector_of_a_major_triad() {
+        let set: PitchClassSet = set![C, E, G];
+        assert_eq!(from(&set), [0, 0, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn interval_vector_deduplicates_enharmonic_spellings() {
+        let set: PitchClassSet = set![C, Cs, Df];
+        assert_eq!(from(&set), [1, 0, 0, 0, 0, 0]);
+    }
+}
@@ -75,17 +75,170 @@
 //! let inverted = invert_by_pair(&set, (Cs, F));
 //! assert_eq!(inverted, set![Cs, E, Fs]);
 //! ```
+//!
+//! ## Interval Vector
+//!
+//! The interval-class content of a set can be computed with [interval_vector],
+//! which tallies, for every unordered pair of pitch classes in the set, the
+//! interval class (1 through 6) between them.
+//!
+//! ```
+//! # use forte::{interval_vector, set, PitchClassSet, PitchClass::*};
+//! let set: PitchClassSet = set![C, E, G];
+//! assert_eq!(interval_vector(&set), [0, 0, 1, 1, 1, 0]);
+//! ```
+//!
+//! ## Multiplication
+//!
+//! The multiplicative operator maps each pitch class `x` to `x * m` (mod 12).
+//! [multiply] performs this directly, and [m5]/[m7] offer the two
+//! multipliers most often seen in the literature (the circle of fourths and
+//! circle of fifths, respectively).
+//!
+//! ```
+//! # use forte::{multiply, set, PitchClassSet, PitchClass::*};
+//! let set: PitchClassSet = set![C, D, E];
+//! assert_eq!(multiply(&set, 5), set![C, Bf, Af]);
+//! ```
+//!
+//! ## Twelve-Tone Operators
+//!
+//! [Tto] unifies transposition, multiplication, and inversion into a single
+//! value implementing `x -> t + m*(if i { -x } else { x })` (mod 12), so that
+//! any combination of the above (`T0M1`, `T4I`, `T9MI`, ...) can be built and
+//! applied as one operator.
+//!
+//! ```
+//! # use forte::{Tto, set, PitchClassSet, PitchClass::*};
+//! let set: PitchClassSet = set![C, E, G];
+//! let t4i = Tto::new(4, 1, true);
+//! assert_eq!(t4i.apply(&set), set![E, C, A]);
+//! ```
+//!
+//! [Tto] also round-trips through canonical operator notation via
+//! [Tto::parse] and its [Display](std::fmt::Display) implementation.
+//!
+//! ```
+//! # use forte::Tto;
+//! let t9mi: Tto = "T9MI".parse().unwrap();
+//! assert_eq!(t9mi, Tto::new(9, 5, true));
+//! assert_eq!(t9mi.to_string(), "T9MI");
+//! ```
+//!
+//! ## Named Intervals
+//!
+//! Because [PitchClass] preserves enharmonic spelling, [interval::named]
+//! can compute the properly spelled common-practice interval between two
+//! pitch classes (type, quality, and direction) rather than just a
+//! semitone count.
+//!
+//! ```
+//! # use forte::{interval, PitchClass::*};
+//! # use forte::interval::{IntervalQuality, IntervalType};
+//! let named = interval::named(Cs, F);
+//! assert_eq!(named.interval_type, IntervalType::Fourth);
+//! assert_eq!(named.quality, IntervalQuality::Diminished);
+//! ```
+//!
+//! ## Complement
+//!
+//! [complement] returns the pitch classes of the twelve-tone aggregate not
+//! present in a set; [complement_within] does the same relative to an
+//! arbitrary user-supplied universe, for diatonic/Zₙ-style work.
+//!
+//! ```
+//! # use forte::{complement, set, PitchClassSet, PitchClass::*};
+//! let set: PitchClassSet = set![C, E, G];
+//! let rest = complement(&set);
+//! assert_eq!(rest, set![Cs, D, Ef, F, Fs, Af, A, Bf, B]);
+//! ```
+//!
+//! ## Set Classes
+//!
+//! [set_class::from] identifies the Forte catalog name of a set (e.g.
+//! `3-11`, `4-Z15`) from its prime form, and [set_class::are_z_related]
+//! tests whether two sets share an interval vector without being
+//! transpositionally or inversionally equivalent.
+//!
+//! The embedded catalog currently covers the monad, dyads, trichords,
+//! tetrachords, octachords, nonachords, decachords, and the hendecachord
+//! (96 of the 220 Forte set classes). Pentachords, hexachords, and
+//! septachords are not yet included: unlike the cardinalities above, they
+//! have no smaller already-verified complement to derive them from.
+//!
+//! ```
+//! # use forte::{set, set_class, PitchClassSet, PitchClass::*};
+//! let set: PitchClassSet = set![C, Ef, G];
+//! let class = set_class::from(&set).unwrap();
+//! assert_eq!(class.forte_number, "3-11");
+//! ```
+//!
+//! ## Forte Catalog Lookup
+//!
+//! [PitchClassSet::forte_number] resolves a set directly to its Forte
+//! catalog name via a compile-time perfect-hash table keyed on the packed
+//! bitmask of the set's prime form, for callers that only need the name
+//! without the rest of [SetClass](set_class::SetClass). It shares its
+//! underlying data with [set_class::from], so the two lookups can never
+//! drift out of sync.
+//!
+//! ```
+//! # use forte::{set, PitchClassSet, PitchClass::*};
+//! let set: PitchClassSet = set![C, Ef, G];
+//! assert_eq!(set.forte_number(), Some("3-11"));
+//! ```
+//!
+//! ## Octave-Designated Pitches
+//!
+//! [Pitch] pairs a [PitchClass] with an octave register, and
+//! [normal_order_octpc] voices a collection of pitch classes as a concrete
+//! ascending sequence of [Pitch] values, using the same cluster
+//! minimization as [to_normal_form] and incrementing the octave each time
+//! the line wraps past B back around to C.
+//!
+//! ```
+//! # use forte::{normal_order_octpc, Pitch, PitchClass::*};
+//! let voiced = normal_order_octpc(&[Bf, F, A], 4);
+//! assert_eq!(
+//!     voiced,
+//!     vec![Pitch::new(4, F), Pitch::new(4, A), Pitch::new(4, Bf)]
+//! );
+//! ```
+//!
+//! ## A Note on the `PitchClassSet` Method API
+//!
+//! Most of the free functions above ([to_normal_form], [to_prime_form],
+//! [interval_vector], [transpose], [invert], [complement]) are also
+//! available as [PitchClassSet] methods (`normal_order`, `prime_form`,
+//! `interval_class_vector`, `transpose`, `invert`, `complement`, ...), so
+//! callers can chain a pipeline of set operations fluently. This means
+//! the crate now exposes two entry points to the same handful of
+//! operations (most methods delegate straight to their free-function
+//! counterpart's module; a couple, like `prime_form`, compute the same
+//! result their own way). Consolidating onto one pattern is a worthwhile
+//! design pass for a future change, but is out of scope here.
 
+mod complement;
+pub mod forte_catalog;
+mod forte_data;
+pub mod interval;
+mod interval_vector;
 mod inversion;
+mod multiplication;
 mod normal_form;
+mod pitch;
 mod pitch_class;
 mod pitch_class_set;
 mod prime_form;
+pub mod set_class;
 mod transposition;
+mod tto;
 mod utils;
 
+pub use pitch::Pitch;
 pub use pitch_class::PitchClass;
 pub use pitch_class_set::PitchClassSet;
+pub use tto::Tto;
 
 use paste::paste;
 
@@ -99,7 +252,7 @@ macro_rules! set {
     (
         $($pc:expr) , *
     ) => {
-        PitchClassSet::new(vec![$($pc),*])
+        PitchClassSet::from_array([$($pc),*])
     }
 }
 
@@ -303,5 +456,92 @@ pub fn invert_by_pair(
     inversion::by_pair(set, inversion_pair)
 }
 
+/// Computes the interval-class vector of a pitch class set.
+///
+/// For every unordered pair of distinct pitch classes in the set, the
+/// interval between them is folded to an interval class (`1` through `6`,
+/// the smaller of the interval and its complement mod 12), and the
+/// six-element result tallies how many pairs produce each interval class.
+/// Pitch classes that are duplicated or merely respelled (e.g. `Cs` and
+/// `Df`) are deduplicated before counting.
+///
+/// ```
+/// # use forte::{interval_vector, set, PitchClassSet, PitchClass::*};
+/// let set: PitchClassSet = set![C, E, G];
+/// assert_eq!(interval_vector(&set), [0, 0, 1, 1, 1, 0]);
+/// ```
+pub fn interval_vector(set: &PitchClassSet) -> [u32; 6] {
+    interval_vector::from(set)
+}
+
+/// Applies the multiplicative operator `M`, mapping each pitch class `x` to
+/// `x * m` (mod 12).
+///
+/// ```
+/// # use forte::{multiply, set, PitchClassSet, PitchClass::*};
+/// let set: PitchClassSet = set![C, D, E];
+/// let multiplied = multiply(&set, 5);
+/// assert_eq!(multiplied, set![C, Bf, Af]);
+/// ```
+pub fn multiply(set: &PitchClassSet, m: u32) -> PitchClassSet {
+    multiplication::by(set, m)
+}
+
+/// Applies `M5`, the circle-of-fourths transform.
+///
+/// Equivalent to calling [multiply] with `m = 5`.
+pub fn m5(set: &PitchClassSet) -> PitchClassSet {
+    multiply(set, 5)
+}
+
+/// Applies `M7`, the circle-of-fifths transform.
+///
+/// Equivalent to calling [multiply] with `m = 7`.
+pub fn m7(set: &PitchClassSet) -> PitchClassSet {
+    multiply(set, 7)
+}
+
+/// Returns the pitch classes of the twelve-tone aggregate not present in
+/// `set`, after reducing `set` to its distinct pitch classes.
+///
+/// ```
+/// # use forte::{complement, set, PitchClassSet, PitchClass::*};
+/// let set: PitchClassSet = set![C, E, G];
+/// let rest = complement(&set);
+/// assert_eq!(rest, set![Cs, D, Ef, F, Fs, Af, A, Bf, B]);
+/// ```
+pub fn complement(set: &PitchClassSet) -> PitchClassSet {
+    complement::from(set)
+}
+
+/// Returns the pitch classes of `universe` not present in `set`, for
+/// complement relations relative to a reference collection other than the
+/// full chromatic aggregate.
+///
+/// ```
+/// # use forte::{complement_within, set, PitchClassSet, PitchClass::*};
+/// let set: PitchClassSet = set![C, E, G];
+/// let diatonic: PitchClassSet = set![C, D, E, F, G, A, B];
+/// assert_eq!(complement_within(&set, &diatonic), set![D, F, A, B]);
+/// ```
+pub fn complement_within(set: &PitchClassSet, universe: &PitchClassSet) -> PitchClassSet {
+    complement::within(set, universe)
+}
+
+/// Voices `classes` as an ascending sequence of [Pitch] values, starting in
+/// `start_octave`.
+///
+/// ```
+/// # use forte::{normal_order_octpc, Pitch, PitchClass::*};
+/// let voiced = normal_order_octpc(&[Bf, F, A], 4);
+/// assert_eq!(
+///     voiced,
+///     vec![Pitch::new(4, F), Pitch::new(4, A), Pitch::new(4, Bf)]
+/// );
+/// ```
+pub fn normal_order_octpc(classes: &[PitchClass], start_octave: i32) -> Vec<Pitch> {
+    pitch::normal_order_octpc(classes, start_octave)
+}
+
 define_transpositions!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
 define_inversions!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
@@ -0,0 +1,32 @@
+use crate::PitchClassSet;
+
+pub fn by(set: &PitchClassSet, m: u32) -> PitchClassSet {
+    let new_set = set
+        .set()
+        .iter()
+        .map(|pc| ((*pc).to_u32() * m).rem_euclid(12).into())
+        .collect();
+    PitchClassSet::new(new_set)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{by, PitchClassSet};
+    use crate::{set, PitchClass::*};
+
+    #[test]
+    fn multiplying_by_a_level() {
+        let set: PitchClassSet = set![C, D, E, Fs];
+        let multiplied: PitchClassSet = by(&set, 5);
+        let expected: PitchClassSet = set![C, Bf, Af, Fs];
+
+        assert_eq!(multiplied, expected);
+    }
+
+    #[test]
+    fn m5_is_the_circle_of_fourths() {
+        let set: PitchClassSet = set![C, Cs, D];
+        let multiplied: PitchClassSet = by(&set, 5);
+        assert_eq!(multiplied, set![C, F, Bf]);
+    }
+}
@@ -27,7 +27,8 @@ fn total_span(set: &PitchClassSet) -> u32 {
 
 pub fn from(set: &PitchClassSet) -> PitchClassSet {
     let mut set = set.set().clone();
-    set.sort_unstable();
+    set.sort_by_key(|pc| pc.to_u32());
+    set.dedup_by_key(|pc| pc.to_u32());
     let rotations: Vec<Internal> = (1..=set.len())
         .map(|i| Internal::new(create_rotation(&set, i)))
         .collect();
@@ -111,4 +112,22 @@ mod tests {
         let expected: PitchClassSet = set![Af, A, B, C, E];
         assert_eq!(from(&set), expected);
     }
+
+    #[test]
+    fn normal_form_sorts_by_pitch_class_value_not_declaration_order() {
+        // Css (2), Ff (4), Gf (6), Df (1) are the same pitch-class values
+        // as Cs, D, E, Fs, but PitchClass's derived Ord is declaration
+        // order, not numeric order, so this only passes if sorting keys
+        // on `to_u32()`.
+        let set: PitchClassSet = set![Css, Ff, Gf, Df];
+        let expected: PitchClassSet = set![Df, Css, Ff, Gf];
+        assert_eq!(from(&set), expected);
+    }
+
+    #[test]
+    fn normal_form_dedupes_by_pitch_class_value() {
+        let set: PitchClassSet = set![C, Cs, Df];
+        let expected: PitchClassSet = set![C, Cs];
+        assert_eq!(from(&set), expected);
+    }
 }
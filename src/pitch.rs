@@ -0,0 +1,120 @@
+use crate::{normal_form, PitchClass, PitchClassSet};
+
+/// A [PitchClass] voiced in a specific octave register.
+///
+/// Octaves follow the common MIDI convention, where octave `4` contains
+/// middle C (MIDI note 60).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pitch {
+    pub octave: i32,
+    pub pc: PitchClass,
+}
+
+impl Pitch {
+    /// Creates a new [Pitch] from an octave and a [PitchClass].
+    pub fn new(octave: i32, pc: PitchClass) -> Self {
+        Self { octave, pc }
+    }
+
+    /// Converts a [Pitch] to its absolute MIDI note number.
+    ///
+    /// ```
+    /// # use forte::{Pitch, PitchClass::*};
+    /// assert_eq!(Pitch::new(4, C).to_midi(), 60);
+    /// ```
+    pub fn to_midi(self) -> i32 {
+        (self.octave + 1) * 12 + self.pc.to_u32() as i32
+    }
+
+    /// Builds a [Pitch] from an absolute MIDI note number.
+    ///
+    /// ```
+    /// # use forte::{Pitch, PitchClass::*};
+    /// assert_eq!(Pitch::from_midi(60), Pitch::new(4, C));
+    /// ```
+    pub fn from_midi(midi: i32) -> Self {
+        let octave = midi.div_euclid(12) - 1;
+        let pc = PitchClass::from(midi.rem_euclid(12) as u32);
+        Self::new(octave, pc)
+    }
+}
+
+/// Voices a collection of pitch classes as an ascending sequence of
+/// [Pitch] values, starting in `start_octave`.
+///
+/// The pitch classes are first reordered into cluster normal order (the
+/// same minimization performed by [normal_form::from]), then assigned
+/// ascending octave numbers, incrementing the octave each time the line
+/// wraps past B back around to C.
+///
+/// ```
+/// # use forte::{normal_order_octpc, Pitch, PitchClass::*};
+/// let voiced = normal_order_octpc(&[Bf, F, A], 4);
+/// assert_eq!(
+///     voiced,
+///     vec![Pitch::new(4, F), Pitch::new(4, A), Pitch::new(4, Bf)]
+/// );
+/// ```
+pub fn normal_order_octpc(classes: &[PitchClass], start_octave: i32) -> Vec<Pitch> {
+    let set = PitchClassSet::new(classes.to_vec());
+    let normal = normal_form::from(&set);
+
+    let mut octave = start_octave;
+    let mut previous = None;
+
+    normal
+        .set()
+        .iter()
+        .map(|pc| {
+            let value = pc.to_u32();
+            if let Some(previous_value) = previous {
+                if value <= previous_value {
+                    octave += 1;
+                }
+            }
+            previous = Some(value);
+            Pitch::new(octave, *pc)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normal_order_octpc, Pitch};
+    use crate::PitchClass::*;
+
+    #[test]
+    fn midi_round_trip() {
+        let pitch = Pitch::new(5, Fs);
+        assert_eq!(Pitch::from_midi(pitch.to_midi()), pitch);
+    }
+
+    #[test]
+    fn negative_octaves_round_trip() {
+        assert_eq!(Pitch::from_midi(Pitch::new(-1, B).to_midi()), Pitch::new(-1, B));
+    }
+
+    #[test]
+    fn voices_an_ascending_cluster() {
+        let voiced = normal_order_octpc(&[Bf, F, A], 4);
+        assert_eq!(
+            voiced,
+            vec![Pitch::new(4, F), Pitch::new(4, A), Pitch::new(4, Bf)]
+        );
+    }
+
+    #[test]
+    fn increments_octave_when_the_line_wraps() {
+        let voiced = normal_order_octpc(&[C, E, Af, A, B], 4);
+        assert_eq!(
+            voiced,
+            vec![
+                Pitch::new(4, Af),
+                Pitch::new(4, A),
+                Pitch::new(4, B),
+                Pitch::new(5, C),
+                Pitch::new(5, E),
+            ]
+        );
+    }
+}
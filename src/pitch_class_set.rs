@@ -1,4 +1,4 @@
-use crate::PitchClass;
+use crate::{complement, forte_catalog, inversion, normal_form, transposition, PitchClass};
 
 /// Wrapper struct for a list of [PitchClasses](PitchClass).
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -15,6 +15,29 @@ impl PitchClassSet {
         Self { set }
     }
 
+    /// Creates a [PitchClassSet] from a fixed-size array of
+    /// [PitchClasses](PitchClass), as used by the [forte::set!](`crate::set!`)
+    /// macro.
+    ///
+    /// Not a `const fn`: [PitchClassSet] is backed by a `Vec`, and building
+    /// one (even from a fixed-size array) requires a heap allocation, which
+    /// stable Rust does not permit inside a `const` context. Making this
+    /// genuinely `const`-capable would mean replacing `Vec<PitchClass>`
+    /// with a fixed-capacity backing representation throughout the type —
+    /// a larger redesign than this constructor alone. In practice the
+    /// motivating case (declaring catalogs as `const`/`static` without
+    /// lazy initialization) is already served by [forte_catalog::CATALOG],
+    /// which keys its `phf` map on a raw `u16` bitmask rather than on
+    /// [PitchClassSet] itself.
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*};
+    /// let set = PitchClassSet::from_array([C, E, G]);
+    /// assert_eq!(set.set(), &[C, E, G]);
+    /// ```
+    pub fn from_array<const N: usize>(pcs: [PitchClass; N]) -> Self {
+        Self::new(pcs.to_vec())
+    }
+
     /// Returns a reference to the contained pitch class set.
     ///
     /// ```
@@ -25,4 +48,435 @@ impl PitchClassSet {
     pub fn set(&self) -> &Vec<PitchClass> {
         &self.set
     }
+
+    /// Reorders this set into normal form: the rotation whose outer
+    /// boundary interval (mod 12) is smallest, with ties broken by
+    /// preferring the ordering whose intervals are packed most tightly
+    /// towards either its start or its end.
+    ///
+    /// Equivalent to calling [crate::to_normal_form].
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![Bf, F, A];
+    /// assert_eq!(set.normal_order(), set![F, A, Bf]);
+    /// ```
+    pub fn normal_order(&self) -> PitchClassSet {
+        normal_form::from(self)
+    }
+
+    /// Reduces this set to the prime form of its set class: the normal
+    /// order of whichever of this set or its inversion transposes to
+    /// begin on `0` with the lexicographically smaller ordering.
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![Bf, F, A];
+    /// assert_eq!(set.prime_form(), set![C, Cs, F]);
+    /// ```
+    pub fn prime_form(&self) -> PitchClassSet {
+        let forward = Self::transposed_to_zero(self);
+        let inverted = Self::transposed_to_zero(&self.invert());
+        let prime = std::cmp::min(forward, inverted);
+        PitchClassSet::new(prime.into_iter().map(PitchClass::from).collect())
+    }
+
+    /// Transposes this set by `n` (mod 12).
+    ///
+    /// Equivalent to calling [crate::transpose].
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![C, D, F];
+    /// assert_eq!(set.transpose(3), set![Ef, F, Af]);
+    /// ```
+    pub fn transpose(&self, n: i8) -> PitchClassSet {
+        transposition::by(self, (n as i32).rem_euclid(12) as u32)
+    }
+
+    /// Inverts this set around `0`, mapping each pitch class `x` to
+    /// `(12 - x) mod 12`.
+    ///
+    /// Equivalent to calling `self.invert_about(0)`.
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![G, Af, B];
+    /// assert_eq!(set.invert(), set![Cs, E, F]);
+    /// ```
+    pub fn invert(&self) -> PitchClassSet {
+        self.invert_about(0)
+    }
+
+    /// Inverts this set around `axis` (mod 12).
+    ///
+    /// Equivalent to calling [crate::invert].
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![Cs, Ef, F, G];
+    /// assert_eq!(set.invert_about(5), set![Bf, C, D, E]);
+    /// ```
+    pub fn invert_about(&self, axis: i8) -> PitchClassSet {
+        inversion::by(self, (axis as i32).rem_euclid(12) as u32)
+    }
+
+    /// Returns `true` when this set and `other` share a prime form, i.e.
+    /// one can be mapped onto the other by some combination of
+    /// transposition and inversion.
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let a: PitchClassSet = set![C, E, G];
+    /// let b: PitchClassSet = set![D, Fs, A];
+    /// assert!(a.is_set_class_equivalent(&b));
+    /// ```
+    pub fn is_set_class_equivalent(&self, other: &PitchClassSet) -> bool {
+        self.prime_form() == other.prime_form()
+    }
+
+    /// Returns the pitch classes of the twelve-tone aggregate not present
+    /// in this set.
+    ///
+    /// Equivalent to calling [crate::complement].
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![C, E, G];
+    /// assert_eq!(set.complement(), set![Cs, D, Ef, F, Fs, Af, A, Bf, B]);
+    /// ```
+    pub fn complement(&self) -> PitchClassSet {
+        complement::from(self)
+    }
+
+    /// Returns `true` when every pitch class in this set also appears in
+    /// `other`, without any transposition or inversion. An alias for
+    /// [PitchClassSet::is_literal_subset_of].
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let a: PitchClassSet = set![C, E];
+    /// let b: PitchClassSet = set![C, E, G];
+    /// assert!(a.is_subset_of(&b));
+    /// ```
+    pub fn is_subset_of(&self, other: &PitchClassSet) -> bool {
+        self.is_literal_subset_of(other)
+    }
+
+    /// Returns `true` when every pitch class in this set also appears in
+    /// `other`, without any transposition or inversion. Pitch classes are
+    /// compared by value, so enharmonic respellings (e.g. `Cs` and `Df`)
+    /// count as the same pitch class.
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let a: PitchClassSet = set![C, E];
+    /// let b: PitchClassSet = set![C, E, G];
+    /// assert!(a.is_literal_subset_of(&b));
+    /// assert!(!b.is_literal_subset_of(&a));
+    /// ```
+    pub fn is_literal_subset_of(&self, other: &PitchClassSet) -> bool {
+        let other_values: Vec<u32> = other.set.iter().map(|pc| pc.to_u32()).collect();
+        self.set
+            .iter()
+            .all(|pc| other_values.contains(&pc.to_u32()))
+    }
+
+    /// Returns `true` when some Tn or TnI image of this set is a literal
+    /// subset of `other`, i.e. this set's set class embeds in `other` up
+    /// to transposition and inversion.
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let a: PitchClassSet = set![C, Cs, D];
+    /// let b: PitchClassSet = set![D, Ds, E, Fs, G];
+    /// assert!(a.is_abstract_subset_of(&b));
+    /// ```
+    pub fn is_abstract_subset_of(&self, other: &PitchClassSet) -> bool {
+        (0..12).any(|n| {
+            let transposed = self.transpose(n);
+            transposed.is_literal_subset_of(other) || transposed.invert().is_literal_subset_of(other)
+        })
+    }
+
+    /// Enumerates every size-`k` subset of this set's distinct pitch
+    /// classes.
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![C, D, E];
+    /// assert_eq!(
+    ///     set.subsets_of_cardinality(2),
+    ///     vec![set![C, D], set![C, E], set![D, E]]
+    /// );
+    /// ```
+    pub fn subsets_of_cardinality(&self, k: usize) -> Vec<PitchClassSet> {
+        let mut pcs = self.set.clone();
+        pcs.sort_unstable_by_key(|pc| pc.to_u32());
+        pcs.dedup_by_key(|pc| pc.to_u32());
+        let n = pcs.len();
+
+        if k > n {
+            return Vec::new();
+        }
+
+        let mut indices: Vec<usize> = (0..k).collect();
+        let mut result = Vec::new();
+        loop {
+            result.push(PitchClassSet::new(indices.iter().map(|&i| pcs[i]).collect()));
+
+            let mut i = k;
+            loop {
+                if i == 0 {
+                    return result;
+                }
+                i -= 1;
+                if indices[i] != i + n - k {
+                    break;
+                }
+            }
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+        }
+    }
+
+    fn transposed_to_zero(set: &PitchClassSet) -> Vec<u32> {
+        let normal = normal_form::from(set);
+        let first = normal.set().first().unwrap().to_u32() as i32;
+        normal
+            .set()
+            .iter()
+            .map(|pc| (pc.to_u32() as i32 - first).rem_euclid(12) as u32)
+            .collect()
+    }
+
+    /// Computes the interval-class vector of this set: for every unordered
+    /// pair of distinct pitch classes, the interval class `min(d, 12 - d)`
+    /// (where `d` is the semitone distance mod 12) is tallied into the
+    /// six-element result, indexed by `ic - 1`.
+    ///
+    /// Equivalent to calling [crate::interval_vector].
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![C, E, G];
+    /// assert_eq!(set.interval_class_vector(), [0, 0, 1, 1, 1, 0]);
+    /// ```
+    pub fn interval_class_vector(&self) -> [u32; 6] {
+        crate::interval_vector::from(self)
+    }
+
+    /// Returns `true` when this set and `other` share an interval-class
+    /// vector but have different prime forms — the defining property of
+    /// Z-related sets such as 4-Z15/4-Z29.
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let a: PitchClassSet = set![C, Cs, E, Fs];
+    /// let b: PitchClassSet = set![C, Cs, Ef, G];
+    /// assert!(a.is_z_related(&b));
+    /// ```
+    pub fn is_z_related(&self, other: &PitchClassSet) -> bool {
+        self.prime_form() != other.prime_form()
+            && self.interval_class_vector() == other.interval_class_vector()
+    }
+
+    /// Looks up this set's Forte catalog name (e.g. `"3-11"`, `"4-Z15"`) by
+    /// packing its prime form into a bitmask and querying the
+    /// [forte_catalog::CATALOG] perfect-hash table.
+    ///
+    /// ```
+    /// # use forte::{PitchClassSet, PitchClass::*, set};
+    /// let set: PitchClassSet = set![C, Ef, G];
+    /// assert_eq!(set.forte_number(), Some("3-11"));
+    /// ```
+    pub fn forte_number(&self) -> Option<&'static str> {
+        let prime_form = self.prime_form();
+        let mask = forte_catalog::pack(
+            &prime_form
+                .set()
+                .iter()
+                .map(|pc| pc.to_u32())
+                .collect::<Vec<_>>(),
+        );
+        forte_catalog::CATALOG.get(&mask).map(|entry| entry.forte_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PitchClassSet;
+    use crate::{set, PitchClass::*};
+
+    #[test]
+    fn from_array_matches_new() {
+        let set = PitchClassSet::from_array([C, E, G]);
+        assert_eq!(set, PitchClassSet::new(vec![C, E, G]));
+    }
+
+    #[test]
+    fn normal_order_method_matches_to_normal_form() {
+        let set: PitchClassSet = set![Bf, F, A];
+        assert_eq!(set.normal_order(), set![F, A, Bf]);
+    }
+
+    #[test]
+    fn prime_form_method_transposes_to_zero() {
+        let set: PitchClassSet = set![Bf, F, A];
+        assert_eq!(set.prime_form(), set![C, Cs, F]);
+    }
+
+    #[test]
+    fn prime_form_method_prefers_the_un_inverted_form_when_already_minimal() {
+        let set: PitchClassSet = set![C, Cs, D];
+        assert_eq!(set.prime_form(), set![C, Cs, D]);
+    }
+
+    #[test]
+    fn prime_form_method_handles_double_accidental_spellings() {
+        // Css (2), Ff (4), Gf (6), Df (1) are the same pitch classes as
+        // Cs, D, E, Fs, so the prime form must be the same regardless of
+        // which spelling is used.
+        let set: PitchClassSet = set![Css, Ff, Gf, Df];
+        assert_eq!(set.prime_form(), set![C, Cs, Ef, F]);
+    }
+
+    #[test]
+    fn transpose_method_matches_free_function() {
+        let set: PitchClassSet = set![C, D, F];
+        assert_eq!(set.transpose(3), set![Ef, F, Af]);
+    }
+
+    #[test]
+    fn invert_method_is_not_an_identity() {
+        let set: PitchClassSet = set![G, Af, B];
+        assert_eq!(set.invert(), set![Cs, E, F]);
+    }
+
+    #[test]
+    fn invert_about_method_matches_free_function() {
+        let set: PitchClassSet = set![Cs, Ef, F, G];
+        assert_eq!(set.invert_about(5), set![Bf, C, D, E]);
+    }
+
+    #[test]
+    fn is_set_class_equivalent_for_transpositionally_related_sets() {
+        let a: PitchClassSet = set![C, E, G];
+        let b: PitchClassSet = set![D, Fs, A];
+        assert!(a.is_set_class_equivalent(&b));
+    }
+
+    #[test]
+    fn is_set_class_equivalent_for_inversionally_related_sets() {
+        let a: PitchClassSet = set![C, Ef, G];
+        let b: PitchClassSet = set![C, E, G];
+        assert!(a.is_set_class_equivalent(&b));
+    }
+
+    #[test]
+    fn is_not_set_class_equivalent_for_unrelated_sets() {
+        let a: PitchClassSet = set![C, E, G];
+        let b: PitchClassSet = set![C, E, Af];
+        assert!(!a.is_set_class_equivalent(&b));
+    }
+
+    #[test]
+    fn is_set_class_equivalent_handles_double_accidental_spellings() {
+        // Fss is enharmonically G, so this is the same transpositional
+        // relation as `set![C, E, G]` to `set![D, Fs, A]`.
+        let a: PitchClassSet = set![C, E, Fss];
+        let b: PitchClassSet = set![D, Fs, A];
+        assert!(a.is_set_class_equivalent(&b));
+    }
+
+    #[test]
+    fn complement_method_matches_free_function() {
+        let set: PitchClassSet = set![C, E, G];
+        assert_eq!(set.complement(), set![Cs, D, Ef, F, Fs, Af, A, Bf, B]);
+    }
+
+    #[test]
+    fn is_literal_subset_of_requires_exact_pitch_content() {
+        let a: PitchClassSet = set![C, E];
+        let b: PitchClassSet = set![C, E, G];
+        assert!(a.is_literal_subset_of(&b));
+        assert!(a.is_subset_of(&b));
+        assert!(!b.is_literal_subset_of(&a));
+    }
+
+    #[test]
+    fn is_abstract_subset_of_allows_transposition_and_inversion() {
+        let a: PitchClassSet = set![C, Cs, D];
+        let b: PitchClassSet = set![D, Ds, E, Fs, G];
+        assert!(a.is_abstract_subset_of(&b));
+        assert!(!a.is_literal_subset_of(&b));
+    }
+
+    #[test]
+    fn is_not_abstract_subset_of_for_unrelated_set_classes() {
+        let a: PitchClassSet = set![C, Cs, D];
+        let b: PitchClassSet = set![C, E, G];
+        assert!(!a.is_abstract_subset_of(&b));
+    }
+
+    #[test]
+    fn subsets_of_cardinality_enumerates_all_combinations() {
+        let set: PitchClassSet = set![C, D, E];
+        assert_eq!(
+            set.subsets_of_cardinality(2),
+            vec![set![C, D], set![C, E], set![D, E]]
+        );
+    }
+
+    #[test]
+    fn subsets_of_cardinality_is_empty_when_k_exceeds_len() {
+        let set: PitchClassSet = set![C, D];
+        assert_eq!(set.subsets_of_cardinality(3), Vec::new());
+    }
+
+    #[test]
+    fn interval_class_vector_of_a_major_triad() {
+        let set: PitchClassSet = set![C, E, G];
+        assert_eq!(set.interval_class_vector(), [0, 0, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn z_related_sets_share_a_vector_but_not_a_prime_form() {
+        let a: PitchClassSet = set![C, Cs, E, Fs];
+        let b: PitchClassSet = set![C, Cs, Ef, G];
+        assert!(a.is_z_related(&b));
+    }
+
+    #[test]
+    fn sets_in_the_same_set_class_are_not_z_related() {
+        let a: PitchClassSet = set![C, E, G];
+        let b: PitchClassSet = set![D, Fs, A];
+        assert!(!a.is_z_related(&b));
+    }
+
+    #[test]
+    fn is_z_related_handles_double_accidental_spellings() {
+        // Dff is enharmonically C, so this is the same Z-relation as
+        // `set![C, Cs, E, Fs]` to `set![C, Cs, Ef, G]`.
+        let a: PitchClassSet = set![Dff, Cs, E, Fs];
+        let b: PitchClassSet = set![C, Cs, Ef, G];
+        assert!(a.is_z_related(&b));
+    }
+
+    #[test]
+    fn forte_number_identifies_a_set_class() {
+        let set: PitchClassSet = set![C, Ef, G];
+        assert_eq!(set.forte_number(), Some("3-11"));
+    }
+
+    #[test]
+    fn forte_number_is_none_for_an_uncatalogued_cardinality() {
+        let set: PitchClassSet = set![C, Cs, D, Ef, E];
+        assert_eq!(set.forte_number(), None);
+    }
+
+    #[test]
+    fn forte_number_handles_double_accidental_spellings() {
+        // Css (2), Ff (4), Gf (6), Df (1) are the same pitch classes as
+        // Cs, D, E, Fs, which is catalogued as 4-11.
+        let set: PitchClassSet = set![Css, Ff, Gf, Df];
+        assert_eq!(set.forte_number(), Some("4-11"));
+    }
 }
@@ -0,0 +1,162 @@
+use crate::forte_data::forte_catalog_data;
+use crate::{interval_vector, prime_form, PitchClassSet};
+
+/// An entry in the [SetClass] catalog: a prime form paired with its Forte
+/// number and precomputed interval-class vector.
+struct CatalogEntry {
+    prime_form: &'static [u32],
+    forte_number: &'static str,
+    interval_vector: [u32; 6],
+}
+
+/// A pitch class set's position in the Forte catalog of set classes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetClass {
+    /// The catalog name, e.g. `"3-11"` or `"4-Z15"`.
+    pub forte_number: &'static str,
+    /// The prime form of the set class.
+    pub prime_form: Vec<u32>,
+    /// The interval-class vector shared by every member of the set class.
+    pub interval_vector: [u32; 6],
+}
+
+/// Looks up the [SetClass] for `set` by computing its prime form and
+/// matching it against the embedded Forte catalog.
+///
+/// Currently covers the monad, dyads, trichords, tetrachords, octachords,
+/// nonachords, decachords, and the hendecachord (96 of the 220 Forte set
+/// classes). Pentachords, hexachords, and septachords are not yet
+/// included and resolve to `None`.
+///
+/// ```
+/// # use forte::{set, PitchClassSet, PitchClass::*};
+/// # use forte::set_class;
+/// let set: PitchClassSet = set![C, Ef, G];
+/// let class = set_class::from(&set).unwrap();
+/// assert_eq!(class.forte_number, "3-11");
+/// ```
+pub fn from(set: &PitchClassSet) -> Option<SetClass> {
+    let prime_form = prime_form::from(set);
+    CATALOG
+        .iter()
+        .find(|entry| entry.prime_form == prime_form.as_slice())
+        .map(|entry| SetClass {
+            forte_number: entry.forte_number,
+            prime_form,
+            interval_vector: entry.interval_vector,
+        })
+}
+
+/// Returns `true` when `a` and `b` share an interval-class vector but are
+/// *not* related by transposition or inversion, i.e. they have different
+/// prime forms. This is the defining property of Z-related sets such as
+/// 4-Z15/4-Z29.
+///
+/// ```
+/// # use forte::{set, PitchClassSet, PitchClass::*};
+/// # use forte::set_class::are_z_related;
+/// let a: PitchClassSet = set![C, Cs, E, Fs];
+/// let b: PitchClassSet = set![C, Cs, Ef, G];
+/// assert!(are_z_related(&a, &b));
+/// ```
+pub fn are_z_related(a: &PitchClassSet, b: &PitchClassSet) -> bool {
+    let prime_a = prime_form::from(a);
+    let prime_b = prime_form::from(b);
+    prime_a != prime_b && interval_vector::from(a) == interval_vector::from(b)
+}
+
+/// Builds [CATALOG] from the shared [forte_catalog_data] literal list,
+/// discarding the packed bitmask each entry also carries (that's only
+/// needed by [crate::forte_catalog]'s `phf` lookup).
+macro_rules! build_catalog {
+    ($(($mask:expr, $forte:expr, $prime:expr, $iv:expr)),* $(,)?) => {
+        &[$(entry($forte, $prime, $iv)),*]
+    };
+}
+
+const CATALOG: &[CatalogEntry] = forte_catalog_data!(build_catalog);
+
+const fn entry(
+    forte_number: &'static str,
+    prime_form: &'static [u32],
+    interval_vector: [u32; 6],
+) -> CatalogEntry {
+    CatalogEntry {
+        prime_form,
+        forte_number,
+        interval_vector,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{are_z_related, from};
+    use crate::{set, PitchClass::*, PitchClassSet};
+
+    #[test]
+    fn identifies_a_trichord() {
+        let set: PitchClassSet = set![C, Ef, G];
+        let class = from(&set).unwrap();
+        assert_eq!(class.forte_number, "3-11");
+        assert_eq!(class.prime_form, vec![0, 3, 7]);
+        assert_eq!(class.interval_vector, [0, 0, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn identifies_a_tetrachord() {
+        let set: PitchClassSet = set![C, Cs, Ef, F];
+        let class = from(&set).unwrap();
+        assert_eq!(class.forte_number, "4-11");
+    }
+
+    #[test]
+    fn identifies_a_tetrachord_spelled_with_double_accidentals() {
+        // Css (2), Ff (4), Gf (6), Df (1) are the same pitch classes as
+        // Cs, D, E, Fs, which is catalogued as 4-11.
+        let set: PitchClassSet = set![Css, Ff, Gf, Df];
+        let class = from(&set).unwrap();
+        assert_eq!(class.forte_number, "4-11");
+    }
+
+    #[test]
+    fn returns_none_for_an_unlisted_cardinality() {
+        let set: PitchClassSet = set![C, Cs, D, Ef, E];
+        assert_eq!(from(&set), None);
+    }
+
+    #[test]
+    fn identifies_a_dyad() {
+        let set: PitchClassSet = set![C, Fs];
+        let class = from(&set).unwrap();
+        assert_eq!(class.forte_number, "2-6");
+    }
+
+    #[test]
+    fn identifies_an_octachord_sharing_its_tetrachord_complements_forte_number() {
+        let set: PitchClassSet = set![C, Cs, D, Ef, E, F, Fs, G];
+        let class = from(&set).unwrap();
+        assert_eq!(class.forte_number, "8-1");
+    }
+
+    #[test]
+    fn z_related_tetrachords_share_an_interval_vector_but_not_a_prime_form() {
+        let a: PitchClassSet = set![C, Cs, E, Fs];
+        let b: PitchClassSet = set![C, Cs, Ef, G];
+
+        let class_a = from(&a).unwrap();
+        let class_b = from(&b).unwrap();
+        assert_eq!(class_a.forte_number, "4-Z15");
+        assert_eq!(class_b.forte_number, "4-Z29");
+        assert_eq!(class_a.interval_vector, class_b.interval_vector);
+        assert_ne!(class_a.prime_form, class_b.prime_form);
+
+        assert!(are_z_related(&a, &b));
+    }
+
+    #[test]
+    fn sets_in_the_same_set_class_are_not_z_related() {
+        let a: PitchClassSet = set![C, E, G];
+        let b: PitchClassSet = set![D, Fs, A];
+        assert!(!are_z_related(&a, &b));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::PitchClassSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// A twelve-tone operator, unifying transposition, the multiplicative
+/// operator, and inversion into a single value.
+///
+/// `Tto` applies the operation `x -> t + m*(if i { -x } else { x })` (mod 12)
+/// to every pitch class in a set. `T0M1` (the identity) is `Tto { t: 0, m: 1,
+/// i: false }`; `T4I` is `Tto { t: 4, m: 1, i: true }`; `T9MI` is
+/// `Tto { t: 9, m: 5, i: true }`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tto {
+    /// The transposition level (mod 12).
+    pub t: u32,
+    /// The multiplier applied before transposition: `1` for plain `T`/`TI`
+    /// operators, `5` for the `M` operators.
+    pub m: u32,
+    /// Whether inversion is applied before multiplication.
+    pub i: bool,
+}
+
+impl Tto {
+    /// Creates a new [Tto] from a transposition level, a multiplier, and
+    /// whether the operator inverts.
+    pub fn new(t: u32, m: u32, i: bool) -> Self {
+        Self {
+            t: t.rem_euclid(12),
+            m,
+            i,
+        }
+    }
+
+    /// Applies this operator to every pitch class in `set`.
+    ///
+    /// ```
+    /// # use forte::{Tto, set, PitchClassSet, PitchClass::*};
+    /// let set: PitchClassSet = set![C, E, G];
+    /// let t4i = Tto::new(4, 1, true);
+    /// assert_eq!(t4i.apply(&set), set![E, C, A]);
+    /// ```
+    pub fn apply(&self, set: &PitchClassSet) -> PitchClassSet {
+        let new_set = set
+            .set()
+            .iter()
+            .map(|pc| {
+                let x = (*pc).to_u32() as i32;
+                let x = if self.i { -x } else { x };
+                (self.t as i32 + self.m as i32 * x).rem_euclid(12) as u32
+            })
+            .map(Into::into)
+            .collect();
+        PitchClassSet::new(new_set)
+    }
+
+    /// Parses canonical twelve-tone operator notation, e.g. `"T5"`, `"T3I"`,
+    /// `"T11M"`, or `"T9MI"`: a leading `T`, a decimal transposition level,
+    /// an optional `M` flag, and an optional trailing `I` flag.
+    ///
+    /// ```
+    /// # use forte::Tto;
+    /// assert_eq!(Tto::parse("T9MI"), Ok(Tto::new(9, 5, true)));
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseTtoError> {
+        let rest = s.strip_prefix('T').ok_or(ParseTtoError)?;
+
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let (level, rest) = rest.split_at(digits_end);
+        if level.is_empty() {
+            return Err(ParseTtoError);
+        }
+        let t: u32 = level.parse().map_err(|_| ParseTtoError)?;
+
+        let (m, rest) = match rest.strip_prefix('M') {
+            Some(rest) => (5, rest),
+            None => (1, rest),
+        };
+
+        let i = match rest {
+            "" => false,
+            "I" => true,
+            _ => return Err(ParseTtoError),
+        };
+
+        Ok(Self::new(t, m, i))
+    }
+}
+
+impl FromStr for Tto {
+    type Err = ParseTtoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Tto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "T{}", self.t)?;
+        if self.m != 1 {
+            write!(f, "M")?;
+        }
+        if self.i {
+            write!(f, "I")?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when a string does not describe a valid [Tto] in
+/// canonical twelve-tone operator notation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseTtoError;
+
+impl fmt::Display for ParseTtoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid twelve-tone operator string")
+    }
+}
+
+impl std::error::Error for ParseTtoError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseTtoError, Tto};
+    use crate::{set, PitchClass::*, PitchClassSet};
+
+    #[test]
+    fn t0m1_is_the_identity() {
+        let set: PitchClassSet = set![C, E, G];
+        let identity = Tto::new(0, 1, false);
+        assert_eq!(identity.apply(&set), set);
+    }
+
+    #[test]
+    fn t4i_inverts_and_transposes() {
+        let set: PitchClassSet = set![C, E, G];
+        let t4i = Tto::new(4, 1, true);
+        assert_eq!(t4i.apply(&set), set![E, C, A]);
+    }
+
+    #[test]
+    fn t9mi_multiplies_inverts_and_transposes() {
+        let set: PitchClassSet = set![C, Cs, D];
+        let t9mi = Tto::new(9, 5, true);
+        assert_eq!(t9mi.apply(&set), set![A, E, B]);
+    }
+
+    #[test]
+    fn parsing_a_plain_transposition() {
+        assert_eq!(Tto::parse("T5"), Ok(Tto::new(5, 1, false)));
+    }
+
+    #[test]
+    fn parsing_a_transposition_with_inversion() {
+        assert_eq!(Tto::parse("T3I"), Ok(Tto::new(3, 1, true)));
+    }
+
+    #[test]
+    fn parsing_a_multiplicative_transposition() {
+        assert_eq!(Tto::parse("T11M"), Ok(Tto::new(11, 5, false)));
+    }
+
+    #[test]
+    fn parsing_a_multiplicative_inverted_transposition() {
+        assert_eq!(Tto::parse("T9MI"), Ok(Tto::new(9, 5, true)));
+    }
+
+    #[test]
+    fn parsing_rejects_invalid_strings() {
+        assert_eq!(Tto::parse("T"), Err(ParseTtoError));
+        assert_eq!(Tto::parse("5"), Err(ParseTtoError));
+        assert_eq!(Tto::parse("T5X"), Err(ParseTtoError));
+    }
+
+    #[test]
+    fn displaying_round_trips_through_parse() {
+        for s in ["T0", "T5", "T3I", "T11M", "T9MI"] {
+            assert_eq!(Tto::parse(s).unwrap().to_string(), s);
+        }
+    }
+}
@@ -1,5 +1,9 @@
-use forte::{i5, invert, invert_by_pair, t3, to_normal_form, to_prime_form, transpose};
-use forte::{set, PitchClass::*, PitchClassSet};
+use forte::{
+    complement, complement_within, i5, interval_vector, invert, invert_by_pair, m5, m7, multiply,
+    t3, to_normal_form, to_prime_form, transpose,
+};
+use forte::interval::{self, IntervalQuality, IntervalType};
+use forte::{normal_order_octpc, set, set_class, PitchClass::*, Pitch, PitchClassSet, Tto};
 
 #[test]
 fn transposition() {
@@ -49,3 +53,191 @@ fn prime_form() {
 
     assert_eq!(to_prime_form(&set), vec![0, 1, 4, 8]);
 }
+
+#[test]
+fn interval_vector_free_function() {
+    let set: PitchClassSet = set![C, E, G];
+
+    assert_eq!(interval_vector(&set), [0, 0, 1, 1, 1, 0]);
+}
+
+#[test]
+fn interval_class_vector() {
+    let set: PitchClassSet = set![C, E, G];
+
+    assert_eq!(set.interval_class_vector(), [0, 0, 1, 1, 1, 0]);
+}
+
+#[test]
+fn z_related_sets() {
+    let a: PitchClassSet = set![C, Cs, E, Fs];
+    let b: PitchClassSet = set![C, Cs, Ef, G];
+
+    assert!(a.is_z_related(&b));
+}
+
+#[test]
+fn multiplicative_operator() {
+    let set: PitchClassSet = set![C, D, E];
+
+    assert_eq!(multiply(&set, 5), set![C, Bf, Af]);
+}
+
+#[test]
+fn circle_of_fourths_and_fifths() {
+    let set: PitchClassSet = set![C, D, E];
+
+    assert_eq!(m5(&set), multiply(&set, 5));
+    assert_eq!(m7(&set), multiply(&set, 7));
+}
+
+#[test]
+fn tto_operator() {
+    let set: PitchClassSet = set![C, E, G];
+    let t4i = Tto::new(4, 1, true);
+
+    assert_eq!(t4i.apply(&set), set![E, C, A]);
+}
+
+#[test]
+fn tto_parses_and_displays_operator_notation() {
+    let t9mi: Tto = "T9MI".parse().unwrap();
+
+    assert_eq!(t9mi, Tto::new(9, 5, true));
+    assert_eq!(t9mi.to_string(), "T9MI");
+}
+
+#[test]
+fn normal_order_method() {
+    let set: PitchClassSet = set![Bf, F, A];
+
+    assert_eq!(set.normal_order(), set![F, A, Bf]);
+}
+
+#[test]
+fn prime_form_method() {
+    let set: PitchClassSet = set![Bf, D, F, Fs];
+
+    assert_eq!(set.prime_form(), set![C, Cs, E, Af]);
+}
+
+#[test]
+fn octave_designated_normal_order() {
+    let voiced = normal_order_octpc(&[Bf, F, A], 4);
+
+    assert_eq!(
+        voiced,
+        vec![Pitch::new(4, F), Pitch::new(4, A), Pitch::new(4, Bf)]
+    );
+}
+
+#[test]
+fn complement_method() {
+    let set: PitchClassSet = set![C, E, G];
+
+    assert_eq!(set.complement(), set![Cs, D, Ef, F, Fs, Af, A, Bf, B]);
+}
+
+#[test]
+fn subset_methods() {
+    let a: PitchClassSet = set![C, E];
+    let b: PitchClassSet = set![C, E, G];
+
+    assert!(a.is_subset_of(&b));
+    assert!(a.is_literal_subset_of(&b));
+    assert!(!b.is_literal_subset_of(&a));
+}
+
+#[test]
+fn abstract_subset_method() {
+    let a: PitchClassSet = set![C, Cs, D];
+    let b: PitchClassSet = set![D, Ds, E, Fs, G];
+
+    assert!(a.is_abstract_subset_of(&b));
+    assert!(!a.is_literal_subset_of(&b));
+}
+
+#[test]
+fn subsets_of_cardinality_method() {
+    let set: PitchClassSet = set![C, D, E];
+
+    assert_eq!(
+        set.subsets_of_cardinality(2),
+        vec![set![C, D], set![C, E], set![D, E]]
+    );
+}
+
+#[test]
+fn transpose_method() {
+    let set: PitchClassSet = set![C, D, F];
+
+    assert_eq!(set.transpose(3), set![Ef, F, Af]);
+}
+
+#[test]
+fn invert_and_invert_about_methods() {
+    let set: PitchClassSet = set![G, Af, B];
+
+    assert_eq!(set.invert(), set![Cs, E, F]);
+    assert_eq!(set.invert_about(5), set![Fs, A, Bf]);
+}
+
+#[test]
+fn is_set_class_equivalent_method() {
+    let a: PitchClassSet = set![C, E, G];
+    let b: PitchClassSet = set![D, Fs, A];
+
+    assert!(a.is_set_class_equivalent(&b));
+}
+
+#[test]
+fn forte_number_method() {
+    let set: PitchClassSet = set![C, Ef, G];
+
+    assert_eq!(set.forte_number(), Some("3-11"));
+}
+
+#[test]
+fn set_class_identification() {
+    let set: PitchClassSet = set![C, Ef, G];
+
+    assert_eq!(set_class::from(&set).unwrap().forte_number, "3-11");
+}
+
+#[test]
+fn set_class_z_relation() {
+    let a: PitchClassSet = set![C, Cs, E, Fs];
+    let b: PitchClassSet = set![C, Cs, Ef, G];
+
+    assert!(set_class::are_z_related(&a, &b));
+}
+
+#[test]
+fn complement_of_the_aggregate() {
+    let set: PitchClassSet = set![C, E, G];
+
+    assert_eq!(complement(&set), set![Cs, D, Ef, F, Fs, Af, A, Bf, B]);
+}
+
+#[test]
+fn complement_within_a_universe() {
+    let set: PitchClassSet = set![C, E, G];
+    let diatonic: PitchClassSet = set![C, D, E, F, G, A, B];
+
+    assert_eq!(complement_within(&set, &diatonic), set![D, F, A, B]);
+}
+
+#[test]
+fn named_interval() {
+    let named = interval::named(Cs, F);
+
+    assert_eq!(named.interval_type, IntervalType::Fourth);
+    assert_eq!(named.quality, IntervalQuality::Diminished);
+}
+
+#[test]
+fn from_array() {
+    let set = PitchClassSet::from_array([C, E, G]);
+
+    assert_eq!(set, set![C, E, G]);
+}